@@ -3,19 +3,32 @@
 //! This is the main entry point for the FlameLang compiler (`flamec`).
 //! It demonstrates the full compilation pipeline with all 5 layers.
 
-use flamelang::{FlameType, Pipeline, IdentityTransform, ScaleTransform, Transform};
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+
+use flamelang::{
+    construct, BundleSource, ConstructorCall, DataKind, DotProductTransform, FlameError, FlameType,
+    IdentityTransform, Inputs, LexTransform, LowerTransform, ParseTransform, Pipeline,
+    ScaleTransform, Transform,
+};
 
 fn main() {
+    // `flamec repl` drops into the interactive front end; otherwise run the demo.
+    if std::env::args().nth(1).as_deref() == Some("repl") {
+        repl();
+        return;
+    }
+
     println!("FlameLang Compiler v2.0.0");
     println!("Physics-native programming language with 5-layer transformation pipeline");
     println!();
-    
+
     // Demonstrate OOP principles in action
     demonstrate_encapsulation();
     demonstrate_abstraction();
     demonstrate_composition();
     demonstrate_polymorphism();
-    
+
     // Run example compilation
     example_compilation();
 }
@@ -44,15 +57,15 @@ fn demonstrate_abstraction() {
     // Transform trait abstracts the concept of transformation
     let transform = IdentityTransform;
     let input = FlameType::Integer(42);
-    
+
     println!("Transform: {}", transform.name());
     println!("Input: {:?}", input);
-    
+
     match transform.apply(&input) {
         Ok(output) => println!("Output: {:?}", output),
         Err(e) => println!("Error: {}", e),
     }
-    
+
     println!();
 }
 
@@ -60,22 +73,37 @@ fn demonstrate_abstraction() {
 fn demonstrate_composition() {
     println!("=== Composition Demo ===");
     
-    // Pipeline composes multiple transforms
+    // Pipeline composes multiple same-typed transforms into a chain
     let mut pipeline = Pipeline::new("Example Pipeline".to_string());
-    pipeline.add_layer(IdentityTransform);
-    pipeline.add_layer(ScaleTransform { factor: 2.0 });
-    pipeline.add_layer(ScaleTransform { factor: 3.0 });
-    
-    println!("Pipeline '{}' with {} layers", pipeline.name, pipeline.layer_count());
-    
-    let input = FlameType::Integer(5);
-    println!("Input: {:?}", input);
-    
-    match pipeline.execute(input) {
-        Ok(output) => println!("Output after 3 layers: {:?}", output),
+    pipeline.add_stage(IdentityTransform).unwrap();
+    pipeline.add_stage(ScaleTransform { factor: 2.0 }).unwrap();
+    pipeline.add_stage(ScaleTransform { factor: 3.0 }).unwrap();
+
+    println!("Pipeline '{}' with {} stages", pipeline.name, pipeline.stage_count());
+
+    match pipeline.execute(Box::new(FlameType::Integer(5))) {
+        Ok(output) => println!(
+            "Output after 3 stages: {:?}",
+            output.downcast_ref::<FlameType>()
+        ),
         Err(e) => println!("Error: {}", e),
     }
-    
+
+    // Multi-ary routing: a ConstructorCall placed after a BundleSource builds a
+    // Vector from a list of argument values, all inside one pipeline.
+    let mut ctor = Pipeline::new("Constructor Pipeline".to_string());
+    ctor.add_stage(BundleSource { values: vec![] }).unwrap();
+    ctor.add_stage(ConstructorCall {
+        target: DataKind::Vector,
+        args: vec![FlameType::Integer(1), FlameType::Integer(2), FlameType::Integer(3)],
+    })
+    .unwrap();
+
+    match ctor.execute(Box::new(())) {
+        Ok(output) => println!("Constructed: {:?}", output.downcast_ref::<FlameType>()),
+        Err(e) => println!("Error: {}", e),
+    }
+
     println!();
 }
 
@@ -85,23 +113,36 @@ fn demonstrate_polymorphism() {
     
     // Same transform.apply() call works on different types
     let scale = ScaleTransform { factor: 2.0 };
-    
+
     let inputs = vec![
         ("Integer", FlameType::Integer(10)),
         ("Angle", FlameType::new_angle(std::f64::consts::PI / 4.0)),
         ("Vector", FlameType::Vector(vec![1.0, 2.0, 3.0])),
     ];
-    
+
     for (name, input) in inputs {
         println!("Input type: {}", name);
         println!("  Before: {:?}", input);
-        
+
         match scale.apply(&input) {
             Ok(output) => println!("  After:  {:?}", output),
             Err(e) => println!("  Error: {}", e),
         }
     }
-    
+
+    // A genuinely multi-ary geometric op: dot product of two vectors.
+    let dot = DotProductTransform;
+    let pair = Inputs(vec![
+        FlameType::Vector(vec![1.0, 0.0, 0.0]),
+        FlameType::Vector(vec![0.0, 1.0, 0.0]),
+    ]);
+    println!("Input type: Vector x Vector");
+    println!("  Before: {:?}", pair);
+    match dot.apply(&pair) {
+        Ok(output) => println!("  After:  {:?}", output),
+        Err(e) => println!("  Error: {}", e),
+    }
+
     println!();
 }
 
@@ -112,37 +153,30 @@ fn example_compilation() {
     println!("Simple source → Iterative transforms → Complex output");
     println!();
     
-    // Simulated 5-layer pipeline
-    // In production, these would be: Linguistic, Numeric, Geometric, Bound, Symbolic
-    let mut pipeline = Pipeline::new("5-Layer Flamelang Pipeline".to_string());
-    
-    // Layer 1: Linguistic (lexing/parsing) - simulated as identity
-    pipeline.add_layer(IdentityTransform);
-    
-    // Layer 2: Numeric (type inference) - simulated as identity
-    pipeline.add_layer(IdentityTransform);
-    
-    // Layer 3: Geometric (angle/vector ops) - simulated as scale
-    pipeline.add_layer(ScaleTransform { factor: 1.5 });
-    
-    // Layer 4: Bound (validation) - simulated as identity
-    pipeline.add_layer(IdentityTransform);
-    
-    // Layer 5: Symbolic (codegen) - simulated as final scale
-    pipeline.add_layer(ScaleTransform { factor: 2.0 });
-    
+    // A single pipeline that changes representation at each stage:
+    //   String (source) -> Tokens -> FlameIR -> FlameType
+    let mut pipeline = Pipeline::new("Flamelang Frontend".to_string());
+
+    // Linguistic layer: lex raw source into tokens
+    pipeline.add_stage(LexTransform).unwrap();
+
+    // Linguistic/Numeric layer: parse tokens into the typed IR
+    pipeline.add_stage(ParseTransform).unwrap();
+
+    // Symbolic layer: lower the IR down to a FlameType result
+    pipeline.add_stage(LowerTransform).unwrap();
+
     println!("Pipeline: {}", pipeline.name);
-    println!("Layers: {}", pipeline.layer_count());
+    println!("Stages: {}", pipeline.stage_count());
     println!();
-    
-    // Example source value (in production, this would be parsed source code)
-    let source = FlameType::Integer(10);
-    println!("Source value: {:?}", source);
-    
+
+    let source = "angle vector bounded".to_string();
+    println!("Source text: {:?}", source);
+
     // Execute pipeline
-    match pipeline.execute(source) {
+    match pipeline.execute(Box::new(source)) {
         Ok(result) => {
-            println!("Compiled result: {:?}", result);
+            println!("Compiled result: {:?}", result.downcast_ref::<FlameType>());
             println!();
             println!("✓ Compilation successful!");
             println!("Fractal dimension D ≈ 1.55 (H=0.45)");
@@ -157,3 +191,267 @@ fn example_compilation() {
     println!("For detailed OOP explanations, see: OOP_PRINCIPLES.md");
     println!("For fractal theory correlation, see: FRACTAL_THEORY.md");
 }
+
+/// Interactive FlameLang front end (`flamec repl`)
+///
+/// Reads expressions line by line, buffering across lines until the statement
+/// is complete (balanced brackets, no trailing `\`), then evaluates it against
+/// a persistent session so `let` declarations accumulate. Meta-commands start
+/// with `:`.
+fn repl() {
+    println!("FlameLang REPL — type :help for commands, :quit to exit");
+
+    // The session: the accumulated IR plus the bindings introduced by `let`.
+    let mut ir = flamelang::FlameIR::new();
+    let mut env: HashMap<String, FlameType> = HashMap::new();
+
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    let mut buffer = String::new();
+
+    loop {
+        // A continuation prompt once we are mid-statement.
+        print!("{} ", if buffer.is_empty() { "flame>" } else { "....>" });
+        let _ = io::stdout().flush();
+
+        let line = match lines.next() {
+            Some(Ok(line)) => line,
+            _ => break, // EOF
+        };
+
+        // Keep buffering until the statement is syntactically complete.
+        if !buffer.is_empty() {
+            buffer.push('\n');
+        }
+        buffer.push_str(&line);
+        if is_incomplete(&buffer) {
+            // Drop a trailing `\` so the continuation marker is not evaluated.
+            if let Some(stripped) = buffer.trim_end().strip_suffix('\\') {
+                buffer.truncate(stripped.len());
+            }
+            continue;
+        }
+
+        let statement = buffer.trim().to_string();
+        buffer.clear();
+
+        if statement.is_empty() {
+            continue;
+        }
+
+        if let Some(command) = statement.strip_prefix(':') {
+            if handle_meta(command.trim(), &mut ir, &mut env) {
+                break;
+            }
+            continue;
+        }
+
+        match eval_statement(&statement, &mut ir, &mut env) {
+            Ok(value) => println!("{}", describe(&value)),
+            Err(e) => println!("error: {}", e),
+        }
+    }
+}
+
+/// Whether `buffer` is an incomplete statement that needs more input
+fn is_incomplete(buffer: &str) -> bool {
+    let mut depth = 0i32;
+    for c in buffer.chars() {
+        match c {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth -= 1,
+            _ => {}
+        }
+    }
+    depth > 0 || buffer.trim_end().ends_with('\\')
+}
+
+/// Handle a meta-command. Returns `true` when the REPL should exit.
+fn handle_meta(
+    command: &str,
+    ir: &mut flamelang::FlameIR,
+    env: &mut HashMap<String, FlameType>,
+) -> bool {
+    match command.split_whitespace().next() {
+        Some("quit") | Some("q") => return true,
+        Some("help") => {
+            println!(":layers        show the configured pipeline stages");
+            println!(":reset         clear the session (declarations + bindings)");
+            println!(":type <expr>   show the DataKind of <expr> without evaluating");
+            println!(":quit          leave the REPL");
+        }
+        Some("layers") => {
+            let pipeline = frontend_pipeline();
+            println!("Pipeline '{}' stages:", pipeline.name);
+            for (i, name) in pipeline.stage_names().iter().enumerate() {
+                println!("  {}. {}", i, name);
+            }
+        }
+        Some("reset") => {
+            *ir = flamelang::FlameIR::new();
+            env.clear();
+            println!("session cleared");
+        }
+        Some("type") => {
+            let expr = command["type".len()..].trim();
+            if expr.is_empty() {
+                println!("usage: :type <expr>");
+            } else {
+                match infer_kind(expr, env) {
+                    Ok(kind) => println!("{:?}", kind),
+                    Err(e) => println!("error: {}", e),
+                }
+            }
+        }
+        _ => println!("unknown command ':{}' (try :help)", command),
+    }
+    false
+}
+
+/// The standard front-end pipeline, shown by `:layers`
+fn frontend_pipeline() -> Pipeline {
+    let mut pipeline = Pipeline::new("Flamelang Frontend".to_string());
+    pipeline.add_stage(LexTransform).unwrap();
+    pipeline.add_stage(ParseTransform).unwrap();
+    pipeline.add_stage(LowerTransform).unwrap();
+    pipeline
+}
+
+/// Evaluate a top-level statement, recording it in the session IR
+fn eval_statement(
+    statement: &str,
+    ir: &mut flamelang::FlameIR,
+    env: &mut HashMap<String, FlameType>,
+) -> Result<FlameType, FlameError> {
+    if let Some(rest) = statement.strip_prefix("let ") {
+        let (name, expr) = rest.split_once('=').ok_or_else(|| {
+            FlameError::ParseError("expected `let <name> = <expr>`".to_string())
+        })?;
+        let name = name.trim().to_string();
+        let value = eval_expr(expr.trim(), env)?;
+        ir.add_declaration(statement.to_string());
+        ir.add_type(value.clone());
+        env.insert(name, value.clone());
+        Ok(value)
+    } else {
+        let value = eval_expr(statement, env)?;
+        ir.add_expression(statement.to_string());
+        ir.add_type(value.clone());
+        Ok(value)
+    }
+}
+
+/// Evaluate an expression against the current bindings
+///
+/// Supported forms: constructor calls (`vector(1, 2, 3)`, `bounded(5, 0, 10)`,
+/// `angle(1.5)`), variable references, and numeric/boolean literals.
+fn eval_expr(src: &str, env: &HashMap<String, FlameType>) -> Result<FlameType, FlameError> {
+    let s = src.trim();
+
+    if let Some(open) = s.find('(') {
+        if let Some(name) = s.strip_suffix(')') {
+            let target = ctor_kind(name[..open].trim())?;
+            let inner = &s[open + 1..s.len() - 1];
+            let mut args = Vec::new();
+            for part in split_args(inner) {
+                args.push(eval_expr(&part, env)?);
+            }
+            return construct(target, args);
+        }
+    }
+
+    if let Some(value) = env.get(s) {
+        return Ok(value.clone());
+    }
+
+    literal(s)
+}
+
+/// Infer the [`DataKind`] of an expression without evaluating it
+fn infer_kind(src: &str, env: &HashMap<String, FlameType>) -> Result<DataKind, FlameError> {
+    let s = src.trim();
+    if let Some(open) = s.find('(') {
+        if s.ends_with(')') {
+            return ctor_kind(s[..open].trim());
+        }
+    }
+    if let Some(value) = env.get(s) {
+        return Ok(value.kind());
+    }
+    literal(s).map(|v| v.kind())
+}
+
+/// Parse a numeric or boolean literal into a [`FlameType`]
+fn literal(s: &str) -> Result<FlameType, FlameError> {
+    if s == "true" {
+        return Ok(FlameType::Boolean(true));
+    }
+    if s == "false" {
+        return Ok(FlameType::Boolean(false));
+    }
+    if let Ok(n) = s.parse::<i64>() {
+        return Ok(FlameType::Integer(n));
+    }
+    if let Ok(x) = s.parse::<f64>() {
+        // A bare scalar is modelled as a pointwise-bounded value.
+        return FlameType::new_bounded(x, x, x);
+    }
+    Err(FlameError::ParseError(format!(
+        "cannot evaluate expression '{}'",
+        s
+    )))
+}
+
+/// Map a constructor name to the [`DataKind`] it builds
+fn ctor_kind(name: &str) -> Result<DataKind, FlameError> {
+    match name {
+        "angle" => Ok(DataKind::Angle),
+        "vector" => Ok(DataKind::Vector),
+        "bounded" => Ok(DataKind::Bounded),
+        other => Err(FlameError::ParseError(format!(
+            "unknown constructor '{}'",
+            other
+        ))),
+    }
+}
+
+/// Split a comma-separated argument list, respecting nested brackets
+fn split_args(inner: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut current = String::new();
+    for c in inner.chars() {
+        match c {
+            '(' | '[' => {
+                depth += 1;
+                current.push(c);
+            }
+            ')' | ']' => {
+                depth -= 1;
+                current.push(c);
+            }
+            ',' if depth == 0 => {
+                parts.push(current.trim().to_string());
+                current.clear();
+            }
+            _ => current.push(c),
+        }
+    }
+    if !current.trim().is_empty() {
+        parts.push(current.trim().to_string());
+    }
+    parts
+}
+
+/// Render a result value with its inferred kind and bounds
+fn describe(value: &FlameType) -> String {
+    match value {
+        FlameType::Angle(a) => format!("Angle {} in [0, 2π)", a),
+        FlameType::Vector(v) => format!("Vector (dim {}) {:?}", v.len(), v),
+        FlameType::Bounded { value, min, max } => {
+            format!("Bounded {} in [{}, {}]", value, min, max)
+        }
+        FlameType::Integer(n) => format!("Integer {}", n),
+        FlameType::Boolean(b) => format!("Boolean {}", b),
+    }
+}